@@ -3,7 +3,7 @@
 pub mod versioned;
 
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{quote, quote_spanned};
 use syn::{parse_quote, spanned::Spanned as _};
 use synthez::{ParseAttrs, ToTokens};
 
@@ -12,8 +12,9 @@ use synthez::{ParseAttrs, ToTokens};
 /// # Errors
 ///
 /// - If `input` isn't a Rust enum definition;
-/// - If some enum variant is not a single-field tuple struct;
-/// - If failed to parse [`VariantAttrs`].
+/// - If some enum variant has neither a single field, nor a single field
+///   marked with `#[event]` attribute;
+/// - If failed to parse [`Attrs`] or [`VariantAttrs`].
 pub fn derive(input: TokenStream) -> syn::Result<TokenStream> {
     let input = syn::parse2::<syn::DeriveInput>(input)?;
     let definition = Definition::try_from(input)?;
@@ -21,6 +22,17 @@ pub fn derive(input: TokenStream) -> syn::Result<TokenStream> {
     Ok(quote! { #definition })
 }
 
+/// Helper attributes of `#[derive(Event)]` macro placed on an enum.
+#[derive(Debug, Default, ParseAttrs)]
+pub struct Attrs {
+    /// States to statically assert being [`event::Sourced`] by every
+    /// non-ignored enum variant.
+    ///
+    /// [`event::Sourced`]: arcana_core::es::event::Sourced
+    #[parse(value)]
+    pub state: Vec<syn::Type>,
+}
+
 /// Helper attributes of `#[derive(Event)]` macro placed on an enum variant.
 #[derive(Debug, Default, ParseAttrs)]
 pub struct VariantAttrs {
@@ -35,13 +47,91 @@ pub struct VariantAttrs {
     /// Indicator whether to ignore this enum variant for code generation.
     #[parse(ident, alias = skip)]
     pub ignore: Option<syn::Ident>,
+
+    /// Types to generate [`From`] conversions into this enum for, by
+    /// converting them into the type of this enum variant's [`syn::Field`]
+    /// first.
+    #[parse(value)]
+    pub from: Vec<syn::Type>,
+
+    /// Indicator whether to generate [`TryFrom`] conversion of this enum into
+    /// the type of this enum variant's [`syn::Field`] carrying the [`Event`].
+    ///
+    /// [`Event`]: arcana_core::es::event::Event
+    #[parse(ident)]
+    pub try_into: Option<syn::Ident>,
+}
+
+/// Representation of an enum variant considered in `#[derive(Event)]` macro
+/// code generation.
+#[derive(Debug)]
+pub struct Variant {
+    /// [`syn::Ident`](struct@syn::Ident) of this enum variant.
+    pub ident: syn::Ident,
+
+    /// [`syn::Member`] of this enum variant's [`syn::Field`] carrying the
+    /// [`Event`].
+    ///
+    /// [`Event`]: arcana_core::es::event::Event
+    pub field: syn::Member,
+
+    /// Type of this enum variant's [`syn::Field`] carrying the [`Event`].
+    ///
+    /// [`Event`]: arcana_core::es::event::Event
+    pub ty: syn::Type,
+
+    /// Indicator whether the [`Variant::field`] is the only [`syn::Field`] of
+    /// this enum variant.
+    pub is_sole_field: bool,
+
+    /// Indicator whether this enum variant should use [`event::Initialized`]
+    /// rather than [`event::Sourced`].
+    ///
+    /// [`event::Initialized`]: arcana_core::es::event::Initialized
+    /// [`event::Sourced`]: arcana_core::es::event::Sourced
+    pub is_initial: bool,
+
+    /// Types to generate [`From`] conversions into the enum for, via this
+    /// enum variant.
+    pub from: Vec<syn::Type>,
+
+    /// Indicator whether to generate [`TryFrom`] conversion of the enum into
+    /// the [`Variant::ty`].
+    pub try_into: bool,
+}
+
+impl Variant {
+    /// Generates tokens of this enum variant with the given `field` tokens in
+    /// place of its [`Variant::field`].
+    ///
+    /// Other [`syn::Field`]s (if any) are omitted, so the result is always
+    /// usable as a pattern, and, in case of a [`Variant::is_sole_field`], as
+    /// an expression too.
+    #[must_use]
+    pub fn with_field(&self, field: &TokenStream) -> TokenStream {
+        let (var, member) = (&self.ident, &self.field);
+        if !self.is_sole_field {
+            quote! { #var { #member: #field, .. } }
+        } else if matches!(member, syn::Member::Unnamed(_)) {
+            quote! { #var(#field) }
+        } else {
+            quote! { #var { #member: #field } }
+        }
+    }
 }
 
 /// Representation of an enum implementing [`Event`], used for code generation.
 ///
 /// [`Event`]: arcana_core::es::event::Event
 #[derive(Debug, ToTokens)]
-#[to_tokens(append(impl_event, impl_event_sourced, gen_uniqueness_glue_code))]
+#[to_tokens(append(
+    impl_event,
+    impl_event_sourced,
+    impl_from,
+    impl_try_from,
+    gen_sourced_assertions,
+    gen_uniqueness_glue_code
+))]
 pub struct Definition {
     /// [`syn::Ident`](struct@syn::Ident) of this enum's type.
     pub ident: syn::Ident,
@@ -49,19 +139,18 @@ pub struct Definition {
     /// [`syn::Generics`] of this enum's type.
     pub generics: syn::Generics,
 
-    /// Single-[`Field`] [`Variant`]s of this enum to consider in code
-    /// generation, along with the indicator whether this variant should use
-    /// [`event::Initialized`] rather than [`event::Sourced`].
-    ///
-    /// [`event::Initialized`]: arcana_core::es::event::Initialized
-    /// [`event::Sourced`]: arcana_core::es::event::Sourced
-    /// [`Field`]: syn::Field
-    /// [`Variant`]: syn::Variant
-    pub variants: Vec<(syn::Variant, bool)>,
+    /// [`Variant`]s of this enum to consider in code generation.
+    pub variants: Vec<Variant>,
 
     /// Indicator whether this enum has any variants marked with
     /// `#[event(ignore)]` attribute.
     pub has_ignored_variants: bool,
+
+    /// States to statically assert being [`event::Sourced`] by every
+    /// [`Variant`] of this enum.
+    ///
+    /// [`event::Sourced`]: arcana_core::es::event::Sourced
+    pub states: Vec<syn::Type>,
 }
 
 impl TryFrom<syn::DeriveInput> for Definition {
@@ -92,11 +181,14 @@ impl TryFrom<syn::DeriveInput> for Definition {
 
         let has_ignored_variants = variants.len() < data.variants.len();
 
+        let attrs = Attrs::parse_attrs("event", &input)?;
+
         Ok(Self {
             ident: input.ident,
             generics: input.generics,
             variants,
             has_ignored_variants,
+            states: attrs.state,
         })
     }
 }
@@ -107,13 +199,14 @@ impl Definition {
     /// # Errors
     ///
     /// - If [`VariantAttrs`] failed to parse.
-    /// - If [`VariantAttrs::init`] and [`VariantAttrs::ignore`] were specified
-    ///   simultaneously.
-    /// - If [`syn::Variant`] doesn't have exactly one unnamed 1 [`syn::Field`]
-    ///   and is not ignored.
-    fn parse_variant(
-        variant: &syn::Variant,
-    ) -> syn::Result<Option<(syn::Variant, bool)>> {
+    /// - If [`VariantAttrs::init`], [`VariantAttrs::from`] or
+    ///   [`VariantAttrs::try_into`] were specified simultaneously with
+    ///   [`VariantAttrs::ignore`].
+    /// - If [`VariantAttrs::from`] or [`VariantAttrs::try_into`] was specified
+    ///   on a [`syn::Variant`] having multiple [`syn::Field`]s.
+    /// - If [`Definition::parse_event_field()`] fails on a non-ignored
+    ///   [`syn::Variant`].
+    fn parse_variant(variant: &syn::Variant) -> syn::Result<Option<Variant>> {
         let attrs = VariantAttrs::parse_attrs("event", variant)?;
 
         if let Some(init) = &attrs.init {
@@ -125,25 +218,120 @@ impl Definition {
                 ));
             }
         }
+        if let Some(from) = attrs.from.first() {
+            if attrs.ignore.is_some() {
+                return Err(syn::Error::new(
+                    from.span(),
+                    "`from` and `ignore`/`skip` arguments are mutually \
+                     exclusive",
+                ));
+            }
+        }
+        if let Some(try_into) = &attrs.try_into {
+            if attrs.ignore.is_some() {
+                return Err(syn::Error::new(
+                    try_into.span(),
+                    "`try_into` and `ignore`/`skip` arguments are mutually \
+                     exclusive",
+                ));
+            }
+        }
 
         if attrs.ignore.is_some() {
             return Ok(None);
         }
 
-        if variant.fields.len() != 1 {
-            return Err(syn::Error::new(
-                variant.span(),
-                "enum variants must have exactly 1 field",
-            ));
+        let (member, field) = Self::parse_event_field(variant)?;
+        let is_sole_field = variant.fields.len() == 1;
+        if let Some(from) = attrs.from.first() {
+            if !is_sole_field {
+                return Err(syn::Error::new(
+                    from.span(),
+                    "`from` argument is allowed on single-field enum variants \
+                     only",
+                ));
+            }
         }
-        if !matches!(variant.fields, syn::Fields::Unnamed(_)) {
-            return Err(syn::Error::new(
-                variant.span(),
-                "only tuple struct enum variants allowed",
-            ));
+        if let Some(try_into) = &attrs.try_into {
+            if !is_sole_field {
+                return Err(syn::Error::new(
+                    try_into.span(),
+                    "`try_into` argument is allowed on single-field enum \
+                     variants only",
+                ));
+            }
         }
 
-        Ok(Some((variant.clone(), attrs.init.is_some())))
+        Ok(Some(Variant {
+            ident: variant.ident.clone(),
+            field: member,
+            ty: field.ty.clone(),
+            is_sole_field,
+            is_initial: attrs.init.is_some(),
+            from: attrs.from,
+            try_into: attrs.try_into.is_some(),
+        }))
+    }
+
+    /// Finds the [`syn::Field`] of the given [`syn::Variant`] carrying the
+    /// [`Event`], along with its [`syn::Member`].
+    ///
+    /// It's either the single [`syn::Field`] of the [`syn::Variant`], or the
+    /// one marked with `#[event]` attribute.
+    ///
+    /// # Errors
+    ///
+    /// - If multiple [`syn::Field`]s are marked with `#[event]` attribute.
+    /// - If `#[event]` attribute on a [`syn::Field`] has any arguments.
+    /// - If [`syn::Variant`] has no [`syn::Field`]s, or has multiple ones
+    ///   without any of them being marked with `#[event]` attribute.
+    ///
+    /// [`Event`]: arcana_core::es::event::Event
+    fn parse_event_field(
+        variant: &syn::Variant,
+    ) -> syn::Result<(syn::Member, &syn::Field)> {
+        let mut marked = None;
+        for (n, field) in variant.fields.iter().enumerate() {
+            for attr in field.attrs.iter().filter(|a| a.path.is_ident("event"))
+            {
+                if !attr.tokens.is_empty() {
+                    return Err(syn::Error::new(
+                        attr.tokens.span(),
+                        "`#[event]` attribute on a field accepts no arguments",
+                    ));
+                }
+                if marked.is_some() {
+                    return Err(syn::Error::new(
+                        attr.span(),
+                        "only a single field can be marked with `#[event]` \
+                         attribute",
+                    ));
+                }
+                marked = Some((n, field));
+            }
+        }
+
+        let (n, field) = if let Some(marked) = marked {
+            marked
+        } else {
+            let mut fields = variant.fields.iter().enumerate();
+            match (fields.next(), fields.next()) {
+                (Some(sole), None) => sole,
+                _ => {
+                    return Err(syn::Error::new(
+                        variant.span(),
+                        "enum variants must have either exactly 1 field, or a \
+                         single field marked with `#[event]` attribute",
+                    ));
+                }
+            }
+        };
+
+        let member = field.ident.clone().map_or_else(
+            || syn::Member::Unnamed(syn::Index::from(n)),
+            syn::Member::Named,
+        );
+        Ok((member, field))
     }
 
     /// Substitutes the given [`syn::Generics`] with trivial types.
@@ -166,15 +354,21 @@ impl Definition {
 
     /// Generates code to derive [`Event`][0] trait, by simply matching over
     /// each enum variant, which is expected to be itself an [`Event`][0]
-    /// implementer.
+    /// implementer, and calling [`Event::visit_leaves()`][1] of all of them.
     ///
     /// [0]: arcana_core::es::event::Event
+    /// [1]: arcana_core::es::event::Event::visit_leaves()
     #[must_use]
     pub fn impl_event(&self) -> TokenStream {
         let ty = &self.ident;
         let (impl_gens, ty_gens, where_clause) = self.generics.split_for_impl();
 
-        let var = self.variants.iter().map(|v| &v.0.ident).collect::<Vec<_>>();
+        let var = self
+            .variants
+            .iter()
+            .map(|v| v.with_field(&quote! { f }))
+            .collect::<Vec<_>>();
+        let var_ty = self.variants.iter().map(|v| &v.ty);
 
         let unreachable_arm = self.has_ignored_variants.then(|| {
             quote! { _ => unreachable!(), }
@@ -186,7 +380,7 @@ impl Definition {
                 fn name(&self) -> ::arcana::es::event::Name {
                     match self {
                         #(
-                            Self::#var(f) => ::arcana::es::Event::name(f),
+                            Self::#var => ::arcana::es::Event::name(f),
                         )*
                         #unreachable_arm
                     }
@@ -195,11 +389,20 @@ impl Definition {
                 fn version(&self) -> ::arcana::es::event::Version {
                     match self {
                         #(
-                            Self::#var(f) => ::arcana::es::Event::version(f),
+                            Self::#var => ::arcana::es::Event::version(f),
                         )*
                         #unreachable_arm
                     }
                 }
+
+                fn visit_leaves<__V>(visitor: &mut __V)
+                where
+                    __V: ::arcana::es::event::Visitor,
+                {
+                    #(
+                        <#var_ty as ::arcana::es::Event>::visit_leaves(visitor);
+                    )*
+                }
             }
         }
     }
@@ -215,9 +418,9 @@ impl Definition {
         let (_, ty_gens, _) = self.generics.split_for_impl();
         let turbofish_gens = ty_gens.as_turbofish();
 
-        let var_tys = self.variants.iter().map(|(v, is_initial)| {
-            let var_ty = v.fields.iter().next().map(|f| &f.ty);
-            if *is_initial {
+        let var_tys = self.variants.iter().map(|v| {
+            let var_ty = &v.ty;
+            if v.is_initial {
                 quote! { ::arcana::es::event::Initial<#var_ty> }
             } else {
                 quote! { #var_ty }
@@ -231,11 +434,10 @@ impl Definition {
         });
         let (impl_gens, _, where_clause) = ext_gens.split_for_impl();
 
-        let arms = self.variants.iter().map(|(v, is_initial)| {
-            let var = &v.ident;
-            let var_ty = v.fields.iter().next().map(|f| &f.ty);
+        let arms = self.variants.iter().map(|v| {
+            let (var, var_ty) = (v.with_field(&quote! { f }), &v.ty);
 
-            let event = if *is_initial {
+            let event = if v.is_initial {
                 quote! {
                     <::arcana::es::event::Initial<#var_ty>
                      as ::arcana::RefCast>::ref_cast(f)
@@ -244,7 +446,7 @@ impl Definition {
                 quote! { f }
             };
             quote! {
-                #ty #turbofish_gens::#var(f) => {
+                #ty #turbofish_gens::#var => {
                     ::arcana::es::event::Sourced::apply(self, #event);
                 },
             }
@@ -268,6 +470,135 @@ impl Definition {
         }
     }
 
+    /// Generates code to derive [`From`] trait for every type specified in
+    /// `#[event(from(...))]` attribute, by converting it into the enum
+    /// variant's type first.
+    ///
+    /// This allows to construct nested enums right from their leaf
+    /// [`Event`][0]s without multiple `.into()` hops.
+    ///
+    /// [0]: arcana_core::es::event::Event
+    #[must_use]
+    pub fn impl_from(&self) -> TokenStream {
+        let ty = &self.ident;
+        let (impl_gens, ty_gens, where_clause) = self.generics.split_for_impl();
+
+        let into = quote! { ::core::convert::Into::into(ev) };
+        let (var, from_ty): (Vec<_>, Vec<_>) = self
+            .variants
+            .iter()
+            .flat_map(|v| {
+                let var = v.with_field(&into);
+                v.from.iter().map(move |from_ty| (var.clone(), from_ty))
+            })
+            .unzip();
+
+        quote! {
+            #(
+                #[automatically_derived]
+                impl #impl_gens ::core::convert::From<#from_ty>
+                     for #ty #ty_gens #where_clause
+                {
+                    fn from(ev: #from_ty) -> Self {
+                        Self::#var
+                    }
+                }
+            )*
+        }
+    }
+
+    /// Generates code to derive [`TryFrom`] trait of the type of every enum
+    /// variant marked with `#[event(try_into)]` attribute, returning an
+    /// [`event::WrongVariant`][0] error in case the enum holds another variant.
+    ///
+    /// [0]: arcana_core::es::event::WrongVariant
+    #[must_use]
+    pub fn impl_try_from(&self) -> TokenStream {
+        let ty = &self.ident;
+        let (impl_gens, ty_gens, where_clause) = self.generics.split_for_impl();
+        let turbofish_gens = ty_gens.as_turbofish();
+
+        let (var, var_ty): (Vec<_>, Vec<_>) = self
+            .variants
+            .iter()
+            .filter(|v| v.try_into)
+            .map(|v| (v.with_field(&quote! { f }), &v.ty))
+            .unzip();
+        let err_ty = quote! { ::arcana::es::event::WrongVariant<#ty #ty_gens> };
+
+        quote! {
+            #(
+                #[automatically_derived]
+                impl #impl_gens ::core::convert::TryFrom<#ty #ty_gens>
+                     for #var_ty #where_clause
+                {
+                    type Error = #err_ty;
+
+                    #[allow(unreachable_patterns)]
+                    fn try_from(
+                        ev: #ty #ty_gens,
+                    ) -> ::core::result::Result<Self, Self::Error> {
+                        match ev {
+                            #ty #turbofish_gens::#var => {
+                                ::core::result::Result::Ok(f)
+                            }
+                            ev => ::core::result::Result::Err(
+                                ::arcana::es::event::WrongVariant(ev),
+                            ),
+                        }
+                    }
+                }
+            )*
+        }
+    }
+
+    /// Generates code statically asserting that every state specified in
+    /// `#[event(state = ...)]` attribute is [`event::Sourced`][0] by every
+    /// enum [`Variant`], so a missing impl is reported right at the variant
+    /// rather than at the usage site.
+    ///
+    /// [0]: arcana_core::es::event::Sourced
+    #[must_use]
+    pub fn gen_sourced_assertions(&self) -> TokenStream {
+        if self.states.is_empty() {
+            return TokenStream::new();
+        }
+
+        let (impl_gens, _, where_clause) = self.generics.split_for_impl();
+
+        let asserts = self.states.iter().flat_map(|state| {
+            self.variants.iter().map(move |v| {
+                let var_ty = &v.ty;
+                let event = if v.is_initial {
+                    quote! { ::arcana::es::event::Initial<#var_ty> }
+                } else {
+                    quote! { #var_ty }
+                };
+                quote_spanned! { var_ty.span() =>
+                    assert_sourced::<::core::option::Option<#state>, #event>();
+                }
+            })
+        });
+
+        quote! {
+            #[automatically_derived]
+            #[doc(hidden)]
+            const _: () = {
+                #[allow(dead_code)]
+                fn assert_sourced<S, Ev>()
+                where
+                    S: ::arcana::es::event::Sourced<Ev> + ?Sized,
+                    Ev: ?Sized,
+                {}
+
+                #[allow(dead_code)]
+                fn assert_all_sourced #impl_gens () #where_clause {
+                    #( #asserts )*
+                }
+            };
+        }
+    }
+
     /// Generates hidden machinery code used to statically check that all the
     /// [`Event::name`][0]s and [`Event::version`][1]s pairs are corresponding
     /// to a single Rust type.
@@ -286,12 +617,7 @@ impl Definition {
         let ty = &self.ident;
         let (impl_gens, ty_gens, where_clause) = self.generics.split_for_impl();
 
-        let var_ty = self
-            .variants
-            .iter()
-            .flat_map(|v| &v.0.fields)
-            .map(|f| &f.ty)
-            .collect::<Vec<_>>();
+        let var_ty = self.variants.iter().map(|v| &v.ty).collect::<Vec<_>>();
 
         // TODO: Use `Self::__arcana_events()` inside impl instead of type
         //       params substitution, once rust-lang/rust#57775 is resolved:
@@ -339,7 +665,7 @@ impl Definition {
 
             #[automatically_derived]
             #[doc(hidden)]
-            const _: () = ::std::assert!(
+            const _: () = ::core::assert!(
                 !#glue::has_different_types_with_same_name_and_ver(
                     #ty::#ty_subst_gens::__arcana_events(),
                 ),
@@ -382,6 +708,18 @@ mod spec {
                         Self::Chat(f) => ::arcana::es::Event::version(f),
                     }
                 }
+
+                fn visit_leaves<__V>(visitor: &mut __V)
+                where
+                    __V: ::arcana::es::event::Visitor,
+                {
+                    <FileEvent as ::arcana::es::Event>::visit_leaves(
+                        visitor
+                    );
+                    <ChatEvent as ::arcana::es::Event>::visit_leaves(
+                        visitor
+                    );
+                }
             }
 
             #[automatically_derived]
@@ -458,7 +796,7 @@ mod spec {
 
             #[automatically_derived]
             #[doc(hidden)]
-            const _: () = ::std::assert!(
+            const _: () = ::core::assert!(
                 !::arcana::es::event::codegen::
                     has_different_types_with_same_name_and_ver(
                         Event::<>::__arcana_events(),
@@ -501,6 +839,18 @@ mod spec {
                         Self::Chat(f) => ::arcana::es::Event::version(f),
                     }
                 }
+
+                fn visit_leaves<__V>(visitor: &mut __V)
+                where
+                    __V: ::arcana::es::event::Visitor,
+                {
+                    <FileEvent<'a, F> as ::arcana::es::Event>::visit_leaves(
+                        visitor
+                    );
+                    <ChatEvent<'a, C> as ::arcana::es::Event>::visit_leaves(
+                        visitor
+                    );
+                }
             }
 
             #[automatically_derived]
@@ -580,7 +930,7 @@ mod spec {
 
             #[automatically_derived]
             #[doc(hidden)]
-            const _: () = ::std::assert!(
+            const _: () = ::core::assert!(
                 !::arcana::es::event::codegen::
                     has_different_types_with_same_name_and_ver(
                         Event::<'static, (), ()>::__arcana_events(),
@@ -634,6 +984,18 @@ mod spec {
                         _ => unreachable!(),
                     }
                 }
+
+                fn visit_leaves<__V>(visitor: &mut __V)
+                where
+                    __V: ::arcana::es::event::Visitor,
+                {
+                    <FileEvent as ::arcana::es::Event>::visit_leaves(
+                        visitor
+                    );
+                    <ChatEvent as ::arcana::es::Event>::visit_leaves(
+                        visitor
+                    );
+                }
             }
 
             #[automatically_derived]
@@ -705,7 +1067,7 @@ mod spec {
 
             #[automatically_derived]
             #[doc(hidden)]
-            const _: () = ::std::assert!(
+            const _: () = ::core::assert!(
                 !::arcana::es::event::codegen::
                     has_different_types_with_same_name_and_ver(
                         Event::<>::__arcana_events(),
@@ -722,6 +1084,164 @@ mod spec {
         assert_eq!(input_skip, input_ignore);
     }
 
+    #[test]
+    fn derives_from_impls() {
+        let input: syn::DeriveInput = parse_quote! {
+            enum Event<F> {
+                #[event(from(FileCreated<F>, FileUpdated<F>))]
+                File(FileEvent<F>),
+                #[event(init, from = ChatEvent)]
+                Chat(ChatEvent),
+                Message(MessageEvent),
+            }
+        };
+
+        let output = quote! {
+            #[automatically_derived]
+            impl<F> ::core::convert::From<FileCreated<F> > for Event<F> {
+                fn from(ev: FileCreated<F>) -> Self {
+                    Self::File(::core::convert::Into::into(ev))
+                }
+            }
+
+            #[automatically_derived]
+            impl<F> ::core::convert::From<FileUpdated<F> > for Event<F> {
+                fn from(ev: FileUpdated<F>) -> Self {
+                    Self::File(::core::convert::Into::into(ev))
+                }
+            }
+
+            #[automatically_derived]
+            impl<F> ::core::convert::From<ChatEvent> for Event<F> {
+                fn from(ev: ChatEvent) -> Self {
+                    Self::Chat(::core::convert::Into::into(ev))
+                }
+            }
+        };
+
+        let definition = super::Definition::try_from(input).unwrap();
+
+        assert_eq!(definition.impl_from().to_string(), output.to_string());
+    }
+
+    #[test]
+    fn derives_try_from_impls() {
+        let input: syn::DeriveInput = parse_quote! {
+            enum Event<F> {
+                #[event(try_into)]
+                File(FileEvent<F>),
+                Chat(ChatEvent),
+                #[event(init, try_into)]
+                Message(MessageEvent),
+            }
+        };
+
+        let output = quote! {
+            #[automatically_derived]
+            impl<F> ::core::convert::TryFrom<Event<F> > for FileEvent<F> {
+                type Error = ::arcana::es::event::WrongVariant<Event<F> >;
+
+                #[allow(unreachable_patterns)]
+                fn try_from(
+                    ev: Event<F>,
+                ) -> ::core::result::Result<Self, Self::Error> {
+                    match ev {
+                        Event::<F>::File(f) => {
+                            ::core::result::Result::Ok(f)
+                        }
+                        ev => ::core::result::Result::Err(
+                            ::arcana::es::event::WrongVariant(ev),
+                        ),
+                    }
+                }
+            }
+
+            #[automatically_derived]
+            impl<F> ::core::convert::TryFrom<Event<F> > for MessageEvent {
+                type Error = ::arcana::es::event::WrongVariant<Event<F> >;
+
+                #[allow(unreachable_patterns)]
+                fn try_from(
+                    ev: Event<F>,
+                ) -> ::core::result::Result<Self, Self::Error> {
+                    match ev {
+                        Event::<F>::Message(f) => {
+                            ::core::result::Result::Ok(f)
+                        }
+                        ev => ::core::result::Result::Err(
+                            ::arcana::es::event::WrongVariant(ev),
+                        ),
+                    }
+                }
+            }
+        };
+
+        let definition = super::Definition::try_from(input).unwrap();
+
+        assert_eq!(definition.impl_try_from().to_string(), output.to_string());
+    }
+
+    #[test]
+    fn derives_enum_impl_with_multiple_fields_in_variant() {
+        let input: syn::DeriveInput = parse_quote! {
+            enum Event {
+                File {
+                    #[event]
+                    event: FileEvent,
+                    stream: StreamId,
+                },
+                Chat(StreamId, #[event] ChatEvent),
+                Message { event: MessageEvent },
+            }
+        };
+
+        let output = quote! {
+            #[automatically_derived]
+            impl ::arcana::es::Event for Event {
+                fn name(&self) -> ::arcana::es::event::Name {
+                    match self {
+                        Self::File { event: f, .. } =>
+                            ::arcana::es::Event::name(f),
+                        Self::Chat { 1: f, .. } =>
+                            ::arcana::es::Event::name(f),
+                        Self::Message { event: f } =>
+                            ::arcana::es::Event::name(f),
+                    }
+                }
+
+                fn version(&self) -> ::arcana::es::event::Version {
+                    match self {
+                        Self::File { event: f, .. } =>
+                            ::arcana::es::Event::version(f),
+                        Self::Chat { 1: f, .. } =>
+                            ::arcana::es::Event::version(f),
+                        Self::Message { event: f } =>
+                            ::arcana::es::Event::version(f),
+                    }
+                }
+
+                fn visit_leaves<__V>(visitor: &mut __V)
+                where
+                    __V: ::arcana::es::event::Visitor,
+                {
+                    <FileEvent as ::arcana::es::Event>::visit_leaves(
+                        visitor
+                    );
+                    <ChatEvent as ::arcana::es::Event>::visit_leaves(
+                        visitor
+                    );
+                    <MessageEvent as ::arcana::es::Event>::visit_leaves(
+                        visitor
+                    );
+                }
+            }
+        };
+
+        let definition = super::Definition::try_from(input).unwrap();
+
+        assert_eq!(definition.impl_event().to_string(), output.to_string());
+    }
+
     #[test]
     fn errors_on_multiple_fields_in_variant() {
         let input = parse_quote! {
@@ -736,7 +1256,77 @@ mod spec {
 
         let err = super::derive(input).unwrap_err();
 
-        assert_eq!(err.to_string(), "enum variants must have exactly 1 field");
+        assert_eq!(
+            err.to_string(),
+            "enum variants must have either exactly 1 field, or a single \
+             field marked with `#[event]` attribute",
+        );
+    }
+
+    #[test]
+    fn derives_sourced_assertions() {
+        let input: syn::DeriveInput = parse_quote! {
+            #[event(state = Chat, state = Option<File>)]
+            enum Event {
+                #[event(init)]
+                File(FileEvent),
+                Chat(ChatEvent),
+                #[event(ignore)]
+                _NonExhaustive,
+            }
+        };
+
+        let output = quote! {
+            #[automatically_derived]
+            #[doc(hidden)]
+            const _: () = {
+                #[allow(dead_code)]
+                fn assert_sourced<S, Ev>()
+                where
+                    S: ::arcana::es::event::Sourced<Ev> + ?Sized,
+                    Ev: ?Sized,
+                {}
+
+                #[allow(dead_code)]
+                fn assert_all_sourced() {
+                    assert_sourced::<
+                        ::core::option::Option<Chat>,
+                        ::arcana::es::event::Initial<FileEvent>
+                    >();
+                    assert_sourced::<::core::option::Option<Chat>, ChatEvent>();
+                    assert_sourced::<
+                        ::core::option::Option<Option<File> >,
+                        ::arcana::es::event::Initial<FileEvent>
+                    >();
+                    assert_sourced::<
+                        ::core::option::Option<Option<File> >,
+                        ChatEvent
+                    >();
+                }
+            };
+        };
+
+        assert_eq!(
+            super::Definition::try_from(input)
+                .unwrap()
+                .gen_sourced_assertions()
+                .to_string(),
+            output.to_string(),
+        );
+    }
+
+    #[test]
+    fn derives_no_sourced_assertions_without_states() {
+        let input: syn::DeriveInput = parse_quote! {
+            enum Event {
+                File(FileEvent),
+            }
+        };
+
+        assert!(super::Definition::try_from(input)
+            .unwrap()
+            .gen_sourced_assertions()
+            .is_empty());
     }
 
     #[test]
@@ -801,4 +1391,106 @@ mod spec {
             "`init` and `ignore`/`skip` arguments are mutually exclusive",
         );
     }
+
+    #[test]
+    fn errors_on_both_from_and_ignored_variant() {
+        let input = parse_quote! {
+            enum Event {
+                Event1(Event1),
+                #[event(ignore, from(Event3))]
+                Event2(Event2),
+            }
+        };
+
+        let err = super::derive(input).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "`from` and `ignore`/`skip` arguments are mutually exclusive",
+        );
+    }
+
+    #[test]
+    fn errors_on_both_try_into_and_ignored_variant() {
+        let input = parse_quote! {
+            enum Event {
+                Event1(Event1),
+                #[event(skip, try_into)]
+                Event2(Event2),
+            }
+        };
+
+        let err = super::derive(input).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "`try_into` and `ignore`/`skip` arguments are mutually exclusive",
+        );
+    }
+
+    #[test]
+    fn errors_on_multiple_marked_fields_in_variant() {
+        let input = parse_quote! {
+            enum Event {
+                Event1(#[event] Event1, #[event] Event2),
+            }
+        };
+
+        let err = super::derive(input).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "only a single field can be marked with `#[event]` attribute",
+        );
+    }
+
+    #[test]
+    fn errors_on_marked_field_with_arguments() {
+        let input = parse_quote! {
+            enum Event {
+                Event1(#[event(init)] Event1, StreamId),
+            }
+        };
+
+        let err = super::derive(input).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "`#[event]` attribute on a field accepts no arguments",
+        );
+    }
+
+    #[test]
+    fn errors_on_from_in_variant_with_multiple_fields() {
+        let input = parse_quote! {
+            enum Event {
+                #[event(from(Event1))]
+                Event1(#[event] Event1, StreamId),
+            }
+        };
+
+        let err = super::derive(input).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "`from` argument is allowed on single-field enum variants only",
+        );
+    }
+
+    #[test]
+    fn errors_on_try_into_in_variant_with_multiple_fields() {
+        let input = parse_quote! {
+            enum Event {
+                #[event(try_into)]
+                Event1(StreamId, #[event] Event1),
+            }
+        };
+
+        let err = super::derive(input).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "`try_into` argument is allowed on single-field enum variants only",
+        );
+    }
 }
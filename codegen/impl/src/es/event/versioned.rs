@@ -1,11 +1,14 @@
 //! `#[derive(event::Versioned)]` macro implementation.
 
+// `#[derive(ParseAttrs)]` iterates over `Option` for `#[parse(nested)]` fields.
+#![allow(for_loops_over_fallibles)]
+
 use std::num::NonZeroU16;
 
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::spanned::Spanned as _;
-use synthez::{ParseAttrs, Required, ToTokens};
+use synthez::{ParseAttrs, Required, Spanning, ToTokens};
 
 /// Expands `#[derive(event::Versioned)]` macro.
 ///
@@ -26,19 +29,85 @@ pub struct Attrs {
     /// Value of [`event::Versioned::NAME`][0] constant.
     ///
     /// [0]: arcana_core::es::event::Versioned::NAME
-    #[parse(value)]
+    #[parse(value, validate = is_valid_name)]
     pub name: Required<syn::LitStr>,
 
+    /// Namespace to prefix the [`Attrs::name`] with, separated by `.` (dot).
+    #[parse(value, validate = is_valid_namespace)]
+    pub namespace: Option<syn::LitStr>,
+
     /// Value of [`event::Versioned::VERSION`][0] constant.
     ///
     /// [0]: arcana_core::es::event::Versioned::VERSION
     #[parse(value, alias = ver, validate = can_parse_as_non_zero_u16)]
     pub version: Required<syn::LitInt>,
+
+    /// Value of [`event::Versioned::DOC`][0] constant.
+    ///
+    /// [0]: arcana_core::es::event::Versioned::DOC
+    #[parse(nested)]
+    pub doc: Option<Spanning<DocAttrs>>,
+}
+
+/// Helper attributes of `#[event(doc(...))]` argument of
+/// `#[derive(event::Versioned)]` macro.
+#[derive(Debug, Default, ParseAttrs)]
+pub struct DocAttrs {
+    /// Value of [`event::Doc::title`][0] field.
+    ///
+    /// [0]: arcana_core::es::event::Doc::title
+    #[parse(value)]
+    pub title: Option<syn::LitStr>,
+
+    /// Value of [`event::Doc::description`][0] field.
+    ///
+    /// [0]: arcana_core::es::event::Doc::description
+    #[parse(value, alias = desc)]
+    pub description: Option<syn::LitStr>,
+
+    /// Value of [`event::Doc::owner`][0] field.
+    ///
+    /// [0]: arcana_core::es::event::Doc::owner
+    #[parse(value)]
+    pub owner: Option<syn::LitStr>,
+}
+
+/// Checks whether the given [`Attrs::name`] matches the [`is_valid_ident()`]
+/// grammar.
+fn is_valid_name(val: &Required<syn::LitStr>) -> syn::Result<()> {
+    is_valid_ident(val, "name")
+}
+
+/// Checks whether the given [`Attrs::namespace`] (if any) matches the
+/// [`is_valid_ident()`] grammar.
+#[allow(clippy::ref_option)] // required by `#[parse(validate)]` signature
+fn is_valid_namespace(val: &Option<syn::LitStr>) -> syn::Result<()> {
+    val.as_ref()
+        .map_or(Ok(()), |v| is_valid_ident(v, "namespace"))
+}
+
+/// Checks whether the given `lit` value matches the `[a-z0-9._-]+` grammar.
+fn is_valid_ident(lit: &syn::LitStr, what: &str) -> syn::Result<()> {
+    let val = lit.value();
+    let is_valid = !val.is_empty()
+        && val.bytes().all(|b| {
+            b.is_ascii_lowercase()
+                || b.is_ascii_digit()
+                || matches!(b, b'.' | b'_' | b'-')
+        });
+    if is_valid {
+        Ok(())
+    } else {
+        Err(syn::Error::new(
+            lit.span(),
+            format!("event {what} should match `[a-z0-9._-]+` grammar"),
+        ))
+    }
 }
 
 /// Checks whether the given `value` can be parsed as [`NonZeroU16`].
 fn can_parse_as_non_zero_u16(val: &Required<syn::LitInt>) -> syn::Result<()> {
-    syn::LitInt::base10_parse::<NonZeroU16>(&**val).map(drop)
+    syn::LitInt::base10_parse::<NonZeroU16>(val).map(drop)
 }
 
 /// Representation of a struct implementing [`event::Versioned`][0], used for
@@ -54,7 +123,8 @@ pub struct Definition {
     /// [`syn::Generics`] of this structure's type.
     pub generics: syn::Generics,
 
-    /// Value of [`event::Versioned::NAME`][0] constant in the generated code.
+    /// Value of [`event::Versioned::NAME`][0] constant in the generated code,
+    /// already prefixed with [`Attrs::namespace`], if any.
     ///
     /// [0]: arcana_core::es::event::Versioned::NAME
     pub event_name: syn::LitStr,
@@ -64,6 +134,12 @@ pub struct Definition {
     ///
     /// [0]: arcana_core::es::event::Versioned::VERSION
     pub event_version: syn::LitInt,
+
+    /// Value of [`event::Versioned::DOC`][0] constant in the generated code,
+    /// if any.
+    ///
+    /// [0]: arcana_core::es::event::Versioned::DOC
+    pub event_doc: Option<DocAttrs>,
 }
 
 impl TryFrom<syn::DeriveInput> for Definition {
@@ -80,11 +156,21 @@ impl TryFrom<syn::DeriveInput> for Definition {
 
         let attrs = Attrs::parse_attrs("event", &input)?;
 
+        let name = attrs.name.into_inner();
+        let event_name = match attrs.namespace {
+            Some(ns) => syn::LitStr::new(
+                &format!("{}.{}", ns.value(), name.value()),
+                name.span(),
+            ),
+            None => name,
+        };
+
         Ok(Self {
             ident: input.ident,
             generics: input.generics,
-            event_name: attrs.name.into_inner(),
+            event_name,
             event_version: attrs.version.into_inner(),
+            event_doc: attrs.doc.map(Spanning::into_inner),
         })
     }
 }
@@ -100,6 +186,27 @@ impl Definition {
 
         let (event_name, event_ver) = (&self.event_name, &self.event_version);
 
+        let event_doc = self.event_doc.as_ref().map(|doc| {
+            let field = |val: &Option<syn::LitStr>| {
+                val.as_ref().map_or_else(
+                    || quote! { ::core::option::Option::None },
+                    |v| quote! { ::core::option::Option::Some(#v) },
+                )
+            };
+            let (title, description, owner) = (
+                field(&doc.title),
+                field(&doc.description),
+                field(&doc.owner),
+            );
+            quote! {
+                const DOC: ::arcana::es::event::Doc = ::arcana::es::event::Doc {
+                    title: #title,
+                    description: #description,
+                    owner: #owner,
+                };
+            }
+        });
+
         quote! {
             #[automatically_derived]
             impl #impl_gens ::arcana::es::event::Versioned for #ty #ty_gens
@@ -111,6 +218,8 @@ impl Definition {
                 const VERSION: ::arcana::es::event::Version = unsafe {
                     ::arcana::es::event::Version::new_unchecked(#event_ver)
                 };
+
+                #event_doc
             }
         }
     }
@@ -125,7 +234,7 @@ impl Definition {
         let ty = &self.ident;
         let (impl_gens, ty_gens, where_clause) = self.generics.split_for_impl();
 
-        // TODO: Replace `::std::concat!(...)` with `TypeId::of()` once it gets
+        // TODO: Replace `::core::concat!(...)` with `TypeId::of()` once it gets
         //       `const`ified.
         //       https://github.com/rust-lang/rust/issues/77125
         quote! {
@@ -138,6 +247,21 @@ impl Definition {
                 const COUNT: usize = 1;
             }
 
+            #[automatically_derived]
+            #[doc(hidden)]
+            impl #impl_gens ::arcana::es::event::codegen::Unique for
+                 #ty #ty_gens #where_clause
+            {
+                #[doc(hidden)]
+                const ID: &'static str = ::core::concat!(
+                    ::core::file!(),
+                    "_",
+                    ::core::line!(),
+                    "_",
+                    ::core::column!(),
+                );
+            }
+
             #[automatically_derived]
             #[doc(hidden)]
             impl #impl_gens #ty #ty_gens #where_clause {
@@ -147,13 +271,7 @@ impl Definition {
                     [(&'static str, &'static str, u16); 1]
                 {
                     [(
-                        ::std::concat!(
-                            ::std::file!(),
-                            "_",
-                            ::std::line!(),
-                            "_",
-                            ::std::column!(),
-                        ),
+                        <Self as ::arcana::es::event::codegen::Unique>::ID,
                         <Self as ::arcana::es::event::Versioned>::NAME,
                         <Self as ::arcana::es::event::Versioned>::VERSION.get(),
                     )]
@@ -193,6 +311,19 @@ mod spec {
                 const COUNT: usize = 1;
             }
 
+            #[automatically_derived]
+            #[doc(hidden)]
+            impl ::arcana::es::event::codegen::Unique for Event {
+                #[doc(hidden)]
+                const ID: &'static str = ::core::concat!(
+                    ::core::file!(),
+                    "_",
+                    ::core::line!(),
+                    "_",
+                    ::core::column!(),
+                );
+            }
+
             #[automatically_derived]
             #[doc(hidden)]
             impl Event {
@@ -202,13 +333,7 @@ mod spec {
                     [(&'static str, &'static str, u16); 1]
                 {
                     [(
-                        ::std::concat!(
-                            ::std::file!(),
-                            "_",
-                            ::std::line!(),
-                            "_",
-                            ::std::column!(),
-                        ),
+                        <Self as ::arcana::es::event::codegen::Unique>::ID,
                         <Self as ::arcana::es::event::Versioned>::NAME,
                         <Self as ::arcana::es::event::Versioned>::VERSION.get(),
                     )]
@@ -222,6 +347,69 @@ mod spec {
         );
     }
 
+    #[test]
+    fn prefixes_name_with_namespace() {
+        let input: syn::DeriveInput = parse_quote! {
+            #[event(namespace = "chat", name = "created", version = 1)]
+            struct Event;
+        };
+
+        let output = quote! {
+            #[automatically_derived]
+            impl ::arcana::es::event::Versioned for Event {
+                const NAME: ::arcana::es::event::Name = "chat.created";
+
+                // SAFETY: Safe, as checked by proc macro in compile time.
+                const VERSION: ::arcana::es::event::Version = unsafe {
+                    ::arcana::es::event::Version::new_unchecked(1)
+                };
+            }
+        };
+
+        assert_eq!(
+            super::Definition::try_from(input)
+                .unwrap()
+                .impl_event_versioned()
+                .to_string(),
+            output.to_string(),
+        );
+    }
+
+    #[test]
+    fn derives_doc() {
+        let input: syn::DeriveInput = parse_quote! {
+            #[event(name = "event", version = 1)]
+            #[event(doc(owner = "team-chat", desc = "Created."))]
+            struct Event;
+        };
+
+        let output = quote! {
+            #[automatically_derived]
+            impl ::arcana::es::event::Versioned for Event {
+                const NAME: ::arcana::es::event::Name = "event";
+
+                // SAFETY: Safe, as checked by proc macro in compile time.
+                const VERSION: ::arcana::es::event::Version = unsafe {
+                    ::arcana::es::event::Version::new_unchecked(1)
+                };
+
+                const DOC: ::arcana::es::event::Doc = ::arcana::es::event::Doc {
+                    title: ::core::option::Option::None,
+                    description: ::core::option::Option::Some("Created."),
+                    owner: ::core::option::Option::Some("team-chat"),
+                };
+            }
+        };
+
+        assert_eq!(
+            super::Definition::try_from(input)
+                .unwrap()
+                .impl_event_versioned()
+                .to_string(),
+            output.to_string(),
+        );
+    }
+
     #[test]
     fn name_arg_is_required() {
         let input = parse_quote! {
@@ -290,6 +478,36 @@ mod spec {
         assert_eq!(err.to_string(), "number too large to fit in target type",);
     }
 
+    #[test]
+    fn errors_on_invalid_name() {
+        let input = parse_quote! {
+            #[event(name = "Chat.Created", version = 1)]
+            struct Event;
+        };
+
+        let err = super::derive(input).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "event name should match `[a-z0-9._-]+` grammar",
+        );
+    }
+
+    #[test]
+    fn errors_on_empty_namespace() {
+        let input = parse_quote! {
+            #[event(namespace = "", name = "created", version = 1)]
+            struct Event;
+        };
+
+        let err = super::derive(input).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "event namespace should match `[a-z0-9._-]+` grammar",
+        );
+    }
+
     #[test]
     fn errors_on_enum() {
         let input = parse_quote! {
@@ -64,7 +64,6 @@
     clippy::string_add,
     clippy::string_lit_as_bytes,
     clippy::string_slice,
-    clippy::string_to_string,
     clippy::suboptimal_flops,
     clippy::suspicious_operation_groupings,
     clippy::todo,
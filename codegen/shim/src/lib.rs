@@ -64,7 +64,6 @@
     clippy::string_add,
     clippy::string_lit_as_bytes,
     clippy::string_slice,
-    clippy::string_to_string,
     clippy::suboptimal_flops,
     clippy::suspicious_operation_groupings,
     clippy::todo,
@@ -127,6 +126,47 @@ use proc_macro::TokenStream;
 /// >              clause because of `const` evaluation limitations. Should be
 /// >              lifted once [rust-lang/rust#57775] is resolved.
 ///
+/// # Enum attributes
+///
+/// #### `#[event(state = <type>)]` (optional)
+///
+/// Aliases: `#[event(state(<type1>, <type2>, ...))]`
+///
+/// Statically asserts that `Option` of the specified state is
+/// [`event::Sourced`] by every non-ignored enum variant (or by its
+/// [`event::Initial`], if marked with `#[event(init)]`). This way, a missing
+/// impl is reported at compile time right at the enum variant, rather than
+/// the whole [`event::Sourced`] impl of the enum silently not applying.
+///
+/// ```rust,compile_fail,E0277
+/// # use arcana::es::{event, Event};
+/// #
+/// #[derive(event::Versioned)]
+/// #[event(name = "chat", version = 1)]
+/// struct ChatEvent;
+///
+/// #[derive(event::Versioned)]
+/// #[event(name = "message", version = 1)]
+/// struct MessageEvent;
+///
+/// struct Chat;
+///
+/// impl event::Initialized<ChatEvent> for Chat {
+///     fn init(_: &ChatEvent) -> Self {
+///         Self
+///     }
+/// }
+///
+/// // This fails to compile, as `Chat` is not `event::Sourced<MessageEvent>`.
+/// #[derive(Event)]
+/// #[event(state = Chat)]
+/// enum ChatEvents {
+///     #[event(init)]
+///     Chat(ChatEvent),
+///     Message(MessageEvent),
+/// }
+/// ```
+///
 /// # Variant attributes
 ///
 /// #### `#[event(init)]` (optional)
@@ -146,6 +186,117 @@ use proc_macro::TokenStream;
 /// > __WARNING:__ Calling [`Event::name()`][0] or [`Event::version()`][1] on
 /// >              ignored variants will result in [`unreachable!`] panic.
 ///
+/// #### `#[event(from(<type1>, <type2>, ...))]` (optional)
+///
+/// Aliases: `#[event(from = <type>)]`
+///
+/// Use this on a particular enum variant to generate [`From`] impls of the
+/// enum for each of the specified types, converting them via the variant's
+/// type. Useful for nested enums, to construct them right from their leaf
+/// [`Event`]s without multiple `.into()` hops.
+///
+/// ```rust
+/// # use arcana::es::{event, Event};
+/// #
+/// #[derive(event::Versioned)]
+/// #[event(name = "chat.created", version = 1)]
+/// struct ChatCreated;
+///
+/// #[derive(event::Versioned)]
+/// #[event(name = "message.posted", version = 1)]
+/// struct MessagePosted;
+///
+/// #[derive(Event)]
+/// enum ChatEvent {
+///     #[event(init, from(ChatCreated))]
+///     Created(ChatCreated),
+///     #[event(from(MessagePosted))]
+///     MessagePosted(MessagePosted),
+/// }
+///
+/// #[derive(Event)]
+/// enum AnyEvent {
+///     #[event(from(ChatEvent, ChatCreated, MessagePosted))]
+///     Chat(ChatEvent),
+/// }
+///
+/// let ev = AnyEvent::from(MessagePosted);
+/// assert_eq!(ev.name(), "message.posted");
+/// ```
+///
+/// #### `#[event(try_into)]` (optional)
+///
+/// Use this on a particular enum variant to generate a [`TryFrom`] impl of the
+/// variant's type from the enum, returning an [`event::WrongVariant`] error
+/// (holding the original enum value) if the enum holds another variant.
+///
+/// Allowed on single-field enum variants only, so no other fields are silently
+/// discarded by the conversion.
+///
+/// ```rust
+/// # use arcana::es::event::{self, Event};
+/// #
+/// #[derive(Debug, event::Versioned, PartialEq)]
+/// #[event(name = "chat", version = 1)]
+/// struct ChatEvent;
+///
+/// #[derive(Debug, event::Versioned, PartialEq)]
+/// #[event(name = "message", version = 1)]
+/// struct MessageEvent;
+///
+/// #[derive(Debug, Event, PartialEq)]
+/// enum AnyEvent {
+///     #[event(try_into)]
+///     Chat(ChatEvent),
+///     Message(MessageEvent),
+/// }
+///
+/// assert_eq!(
+///     ChatEvent::try_from(AnyEvent::Chat(ChatEvent)),
+///     Ok(ChatEvent)
+/// );
+/// assert_eq!(
+///     ChatEvent::try_from(AnyEvent::Message(MessageEvent)),
+///     Err(event::WrongVariant(AnyEvent::Message(MessageEvent))),
+/// );
+/// ```
+///
+/// # Field attributes
+///
+/// #### `#[event]` (optional)
+///
+/// Use this on a particular field of an enum variant having multiple fields, to
+/// specify the one carrying the [`Event`]. Other fields are ignored in code
+/// generation, so may be used to colocate any related metadata with the
+/// [`Event`].
+///
+/// Not required for enum variants having a single field only.
+///
+/// ```rust
+/// # use arcana::es::{event, Event};
+/// #
+/// # #[derive(event::Versioned)]
+/// # #[event(name = "chat", version = 1)]
+/// # struct ChatEvent;
+/// #
+/// # #[derive(event::Versioned)]
+/// # #[event(name = "message", version = 1)]
+/// # struct MessageEvent;
+/// #
+/// #[derive(Event)]
+/// enum StoredEvent {
+///     Chat {
+///         #[event]
+///         event: ChatEvent,
+///         stream_id: u64,
+///     },
+///     Message(u64, #[event] MessageEvent),
+/// }
+///
+/// let ev = StoredEvent::Message(1, MessageEvent);
+/// assert_eq!(ev.name(), "message");
+/// ```
+///
 /// # Example
 ///
 /// ```rust,compile_fail,E0080
@@ -197,8 +348,10 @@ use proc_macro::TokenStream;
 /// ```
 ///
 /// [`Event`]: arcana_core::es::Event
+/// [`event::Initial`]: arcana_core::es::event::Initial
 /// [`event::Initialized`]: arcana_core::es::event::Initialized
 /// [`event::Sourced`]: arcana_core::es::event::Sourced
+/// [`event::WrongVariant`]: arcana_core::es::event::WrongVariant
 /// [`Versioned`]: arcana_core::es::event::Versioned
 /// [0]: arcana_core::es::Event::name()
 /// [1]: arcana_core::es::Event::version()
@@ -219,7 +372,23 @@ pub fn derive_event(input: TokenStream) -> TokenStream {
 ///
 /// #### `#[event(name = "...")]`
 ///
-/// Value of [`Versioned::NAME`][0] constant.
+/// Value of [`Versioned::NAME`][0] constant. Should match `[a-z0-9._-]+`
+/// grammar.
+///
+/// #### `#[event(namespace = "...")]` (optional)
+///
+/// Prefix of [`Versioned::NAME`][0] constant, separated from the `name` with
+/// `.` (dot). Should match `[a-z0-9._-]+` grammar.
+///
+/// ```rust
+/// # use arcana::es::event::{self, Versioned as _};
+/// #
+/// #[derive(event::Versioned)]
+/// #[event(namespace = "chat", name = "created", version = 1)]
+/// struct ChatCreated;
+///
+/// assert_eq!(ChatCreated::NAME, "chat.created");
+/// ```
 ///
 /// #### `#[event(version = <non-zero-u16>)]`
 ///
@@ -227,6 +396,28 @@ pub fn derive_event(input: TokenStream) -> TokenStream {
 ///
 /// Value of [`Versioned::VERSION`][1] constant.
 ///
+/// #### `#[event(doc(title = "...", description = "...", owner = "..."))]`
+///
+/// Aliases: `#[event(doc(desc = "..."))]`
+///
+/// Value of [`Versioned::DOC`][2] constant, exposed in the catalog via
+/// [`Event::meta()`]. Both the attribute and each of its arguments are
+/// optional.
+///
+/// ```rust
+/// # use arcana::es::event::{self, Event as _};
+/// #
+/// #[derive(event::Versioned)]
+/// #[event(name = "chat.created", version = 1)]
+/// #[event(doc(owner = "team-chat", description = "New chat is created."))]
+/// struct ChatCreated;
+///
+/// let doc = ChatCreated::meta()[0].doc;
+/// assert_eq!(doc.title, None);
+/// assert_eq!(doc.description, Some("New chat is created."));
+/// assert_eq!(doc.owner, Some("team-chat"));
+/// ```
+///
 /// # Example
 ///
 /// ```rust
@@ -237,9 +428,11 @@ pub fn derive_event(input: TokenStream) -> TokenStream {
 /// struct Event;
 /// ```
 ///
+/// [`Event::meta()`]: arcana_core::es::Event::meta()
 /// [`Versioned`]: arcana_core::es::event::Versioned
 /// [0]: arcana_core::es::event::Versioned::NAME
 /// [1]: arcana_core::es::event::Versioned::VERSION
+/// [2]: arcana_core::es::event::Versioned::DOC
 #[proc_macro_derive(VersionedEvent, attributes(event))]
 pub fn derive_versioned_event(input: TokenStream) -> TokenStream {
     codegen::es::event::versioned::derive(input.into())
@@ -0,0 +1,120 @@
+//! Caching of [`Sourced`] states.
+
+use core::ops::Deref;
+
+use derive_more::Deref;
+
+use super::event::Sourced;
+
+/// [`Sourced`] state along with the version of the stream it has been
+/// calculated from.
+///
+/// The stream version is the number of [`Event`]s applied to the state, so
+/// keeping the [`Materialized`] state around allows to [`catch_up()`] with a
+/// stream by applying only the [`Event`]s appended to it since, rather than
+/// folding the whole stream again.
+///
+/// [`catch_up()`]: Materialized::catch_up()
+/// [`Event`]: super::Event
+#[derive(Clone, Copy, Debug, Default, Deref, Eq, Hash, PartialEq)]
+pub struct Materialized<S> {
+    /// Materialized state.
+    #[deref]
+    state: S,
+
+    /// Number of [`Event`]s applied to the [`Materialized::state`].
+    ///
+    /// [`Event`]: super::Event
+    version: u64,
+}
+
+impl<S> Materialized<S> {
+    /// Creates a new [`Materialized`] state out of the given `state` already
+    /// calculated from the stream of the given `version`.
+    #[must_use]
+    pub const fn new(state: S, version: u64) -> Self {
+        Self { state, version }
+    }
+
+    /// Returns the version of the stream this [`Materialized`] state
+    /// corresponds to.
+    ///
+    /// This is the position in the stream to read new [`Event`]s from.
+    ///
+    /// [`Event`]: super::Event
+    #[must_use]
+    pub const fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Unwraps this [`Materialized`] state, dropping its version.
+    #[must_use]
+    pub fn into_inner(self) -> S {
+        self.state
+    }
+
+    /// Applies the given [`Event`] to this [`Materialized`] state, advancing
+    /// its version.
+    ///
+    /// [`Event`]: super::Event
+    pub fn apply<Ev: ?Sized>(&mut self, event: &Ev)
+    where
+        S: Sourced<Ev>,
+    {
+        self.state.apply(event);
+        self.version += 1;
+    }
+
+    /// Catches up this [`Materialized`] state with its stream by applying the
+    /// given `events`, which are expected to be the ones following the current
+    /// [`Materialized::version()`] in the stream.
+    ///
+    /// Returns the new [`Materialized::version()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use arcana::es::{cache::Materialized, event};
+    /// #
+    /// #[derive(Debug, Default, Eq, PartialEq)]
+    /// struct Chat {
+    ///     message_count: usize,
+    /// }
+    ///
+    /// #[derive(event::Versioned)]
+    /// #[event(name = "message.posted", version = 1)]
+    /// struct MessagePosted;
+    ///
+    /// impl event::Sourced<MessagePosted> for Chat {
+    ///     fn apply(&mut self, _: &MessagePosted) {
+    ///         self.message_count += 1;
+    ///     }
+    /// }
+    ///
+    /// let stream = vec![MessagePosted, MessagePosted];
+    ///
+    /// let mut chat = Materialized::<Chat>::default();
+    /// assert_eq!(chat.catch_up(&stream), 2);
+    /// assert_eq!(chat.message_count, 2);
+    ///
+    /// let stream = vec![MessagePosted, MessagePosted, MessagePosted];
+    /// let delta = usize::try_from(chat.version()).unwrap();
+    ///
+    /// assert_eq!(chat.catch_up(&stream[delta..]), 3);
+    /// assert_eq!(chat.message_count, 3);
+    /// ```
+    ///
+    /// [`Event`]: super::Event
+    pub fn catch_up<Ev, I>(&mut self, events: I) -> u64
+    where
+        S: Sourced<Ev>,
+        Ev: ?Sized,
+        I: IntoIterator,
+        I::Item: Deref<Target = Ev>,
+    {
+        for ev in events {
+            self.apply(&*ev);
+        }
+        self.version
+    }
+}
@@ -0,0 +1,298 @@
+//! Compatibility checks between [`Event`] catalogs.
+//!
+//! [`Event`]: super::Event
+
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use derive_more::Display;
+
+use super::event::{Meta, Name, Version};
+
+/// Breaking change detected between two [`Event`] catalogs.
+///
+/// [`Event`]: super::Event
+#[derive(Clone, Copy, Debug, Display, Eq, Hash, PartialEq)]
+pub enum Incompatibility {
+    /// All versions of an [`Event`] with the given [`Name`] are removed.
+    ///
+    /// [`Event`]: super::Event
+    #[display(fmt = "`{_0}` event is removed")]
+    RemovedName(Name),
+
+    /// Concrete [`Version`] of an [`Event`] is removed, while its other
+    /// versions are still present.
+    ///
+    /// [`Event`]: super::Event
+    #[display(fmt = "`{name}` event of version {version} is removed")]
+    RemovedVersion {
+        /// [`Name`] of the removed [`Event`].
+        ///
+        /// [`Event`]: super::Event
+        name: Name,
+
+        /// Removed [`Version`].
+        version: Version,
+    },
+
+    /// [`Version`] of an [`Event`] is skipped between its lowest and highest
+    /// versions, while being neither present nor a gap already in the old
+    /// catalog.
+    ///
+    /// [`Event`]: super::Event
+    #[display(fmt = "`{name}` event misses version {version}")]
+    VersionGap {
+        /// [`Name`] of the [`Event`] having a gap in its versions.
+        ///
+        /// [`Event`]: super::Event
+        name: Name,
+
+        /// Missing [`Version`].
+        version: Version,
+    },
+
+    /// The same [`Name`] and [`Version`] are mapped onto different Rust types.
+    #[display(
+        fmt = "`{name}` event of version {version} is remapped from `{old}` to \
+               `{new}`"
+    )]
+    ConflictingMapping {
+        /// [`Name`] of the remapped [`Event`].
+        ///
+        /// [`Event`]: super::Event
+        name: Name,
+
+        /// [`Version`] of the remapped [`Event`].
+        ///
+        /// [`Event`]: super::Event
+        version: Version,
+
+        /// [`Meta::type_name`] in the old catalog.
+        old: &'static str,
+
+        /// [`Meta::type_name`] in the new catalog.
+        new: &'static str,
+    },
+}
+
+/// Compares the `old` and `new` [`Event`] catalogs (as returned by
+/// [`Event::meta()`]), and returns all the [`Incompatibility`]s the `new` one
+/// introduces, ordered by [`Name`] and [`Version`].
+///
+/// Duplicated entries in catalogs are allowed, and only the first occurrence
+/// of a [`Name`] and [`Version`] pair is considered.
+///
+/// _Note:_ [`Meta::type_name`] is used for detecting
+/// [`Incompatibility::ConflictingMapping`]s, so moving or renaming a Rust type
+/// is reported as well.
+///
+/// # Example
+///
+/// ```rust
+/// # use arcana::es::{compat::{self, Incompatibility}, event::{self, Event}};
+/// #
+/// mod v1 {
+///     # use arcana::es::event;
+///     #
+///     #[derive(event::Versioned)]
+///     #[event(name = "chat.created", version = 1)]
+///     pub struct ChatCreated;
+///
+///     #[derive(event::Versioned)]
+///     #[event(name = "message.posted", version = 1)]
+///     pub struct MessagePosted;
+/// }
+///
+/// #[derive(event::Versioned)]
+/// #[event(name = "chat.created", version = 3)]
+/// struct ChatCreated;
+///
+/// #[derive(Event)]
+/// enum OldEvent {
+///     Created(v1::ChatCreated),
+///     MessagePosted(v1::MessagePosted),
+/// }
+///
+/// #[derive(Event)]
+/// enum NewEvent {
+///     CreatedV1(v1::ChatCreated),
+///     CreatedV3(ChatCreated),
+/// }
+///
+/// let diff = compat::diff(&OldEvent::meta(), &NewEvent::meta());
+/// assert_eq!(
+///     diff.iter().map(ToString::to_string).collect::<Vec<_>>(),
+///     [
+///         "`chat.created` event misses version 2",
+///         "`message.posted` event is removed",
+///     ],
+/// );
+/// ```
+///
+/// [`Event`]: super::Event
+/// [`Event::meta()`]: super::Event::meta()
+#[must_use]
+pub fn diff(old: &[Meta], new: &[Meta]) -> Vec<Incompatibility> {
+    let (old, new) = (catalog(old), catalog(new));
+
+    let mut diff = Vec::new();
+    for (name, old_versions) in &old {
+        let new_versions = if let Some(versions) = new.get(name) {
+            versions
+        } else {
+            diff.push(Incompatibility::RemovedName(name));
+            continue;
+        };
+        for (&version, &old_ty) in old_versions {
+            match new_versions.get(&version) {
+                Some(&new_ty) if new_ty != old_ty => {
+                    diff.push(Incompatibility::ConflictingMapping {
+                        name,
+                        version,
+                        old: old_ty,
+                        new: new_ty,
+                    });
+                }
+                Some(_) => {}
+                None => {
+                    diff.push(Incompatibility::RemovedVersion {
+                        name,
+                        version,
+                    });
+                }
+            }
+        }
+    }
+
+    for (name, new_versions) in &new {
+        let (min, max) =
+            match (new_versions.keys().next(), new_versions.keys().next_back())
+            {
+                (Some(min), Some(max)) => (min.get(), max.get()),
+                _ => continue,
+            };
+        // Versions within the old range are either reported as removed
+        // already, or were gaps in the old catalog too.
+        let old_range = old
+            .get(name)
+            .and_then(|o| Some(*o.keys().next()?..=*o.keys().next_back()?));
+        diff.extend(
+            (min..max)
+                .filter_map(Version::try_new)
+                .filter(|v| !new_versions.contains_key(v))
+                .filter(|v| {
+                    !old_range.as_ref().map_or(false, |r| r.contains(v))
+                })
+                .map(|version| Incompatibility::VersionGap { name, version }),
+        );
+    }
+
+    diff.sort_by_key(|i| match *i {
+        Incompatibility::RemovedName(name) => (name, None),
+        Incompatibility::RemovedVersion { name, version }
+        | Incompatibility::VersionGap { name, version }
+        | Incompatibility::ConflictingMapping { name, version, .. } => {
+            (name, Some(version))
+        }
+    });
+    diff
+}
+
+/// Indexes the given [`Meta`]s by their [`Name`]s and [`Version`]s, keeping
+/// only the first [`Meta::type_name`] of each pair.
+fn catalog(meta: &[Meta]) -> BTreeMap<Name, BTreeMap<Version, &'static str>> {
+    let mut catalog = BTreeMap::<_, BTreeMap<_, _>>::new();
+    // Iterating in reverse, so the first occurrence overwrites the others.
+    for m in meta.iter().rev() {
+        catalog
+            .entry(m.name)
+            .or_default()
+            .extend([(m.version, m.type_name)]);
+    }
+    catalog
+}
+
+#[cfg(test)]
+mod spec {
+    use crate::es::event::Doc;
+
+    use super::{diff, Incompatibility, Meta, Version};
+
+    fn meta(type_name: &'static str, name: &'static str, ver: u16) -> Meta {
+        Meta {
+            type_name,
+            name,
+            version: Version::try_new(ver).unwrap(),
+            doc: Doc::EMPTY,
+        }
+    }
+
+    #[test]
+    fn reports_nothing_for_same_catalogs() {
+        let catalog = [meta("A", "a", 1), meta("B", "b", 1), meta("B", "b", 1)];
+        let gapped = [meta("A1", "a", 1), meta("A3", "a", 3)];
+
+        assert!(diff(&catalog, &catalog).is_empty());
+        assert!(diff(&gapped, &gapped).is_empty());
+    }
+
+    #[test]
+    fn allows_adding_events_and_versions() {
+        let old = [meta("A1", "a", 1)];
+        let new = [meta("A1", "a", 1), meta("A2", "a", 2), meta("B", "b", 1)];
+
+        assert!(diff(&old, &new).is_empty());
+    }
+
+    #[test]
+    fn reports_removed_names_and_versions() {
+        let old = [meta("A1", "a", 1), meta("A2", "a", 2), meta("B", "b", 1)];
+        let new = [meta("A1", "a", 1)];
+
+        assert_eq!(
+            diff(&old, &new),
+            [
+                Incompatibility::RemovedVersion {
+                    name: "a",
+                    version: Version::try_new(2).unwrap(),
+                },
+                Incompatibility::RemovedName("b"),
+            ],
+        );
+    }
+
+    #[test]
+    fn reports_version_gaps_only_once() {
+        let old = [meta("A1", "a", 1), meta("A2", "a", 2)];
+        let new = [meta("A1", "a", 1), meta("A4", "a", 4)];
+
+        assert_eq!(
+            diff(&old, &new),
+            [
+                Incompatibility::RemovedVersion {
+                    name: "a",
+                    version: Version::try_new(2).unwrap(),
+                },
+                Incompatibility::VersionGap {
+                    name: "a",
+                    version: Version::try_new(3).unwrap(),
+                },
+            ],
+        );
+    }
+
+    #[test]
+    fn reports_conflicting_mappings() {
+        let old = [meta("A", "a", 1)];
+        let new = [meta("B", "a", 1), meta("A", "a", 1)];
+
+        assert_eq!(
+            diff(&old, &new),
+            [Incompatibility::ConflictingMapping {
+                name: "a",
+                version: Version::try_new(1).unwrap(),
+                old: "A",
+                new: "B",
+            }],
+        );
+    }
+}
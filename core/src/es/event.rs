@@ -1,6 +1,13 @@
 //! [`Event`] machinery.
 
-use std::num::NonZeroU16;
+use alloc::vec::Vec;
+use core::{any, fmt, num::NonZeroU16, ops::Deref};
+#[cfg(feature = "std")]
+use std::{
+    collections::HashMap,
+    error,
+    hash::{BuildHasher, Hash},
+};
 
 use derive_more::{Deref, DerefMut, Display, Into};
 use ref_cast::RefCast;
@@ -54,6 +61,32 @@ pub trait Versioned {
 
     /// [`Version`] of this [`Event`].
     const VERSION: Version;
+
+    /// [`Doc`]umentation of this [`Event`].
+    const DOC: Doc = Doc::EMPTY;
+}
+
+/// Documentation of a [`Versioned`] [`Event`], intended for governance
+/// tooling.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Doc {
+    /// Short human-readable title of the [`Event`].
+    pub title: Option<&'static str>,
+
+    /// Detailed description of the [`Event`].
+    pub description: Option<&'static str>,
+
+    /// Owner (team, service, etc.) responsible for the [`Event`].
+    pub owner: Option<&'static str>,
+}
+
+impl Doc {
+    /// [`Doc`] without any documentation.
+    pub const EMPTY: Self = Self {
+        title: None,
+        description: None,
+        owner: None,
+    };
 }
 
 /// [Event Sourcing] event describing something that has occurred (happened
@@ -71,6 +104,111 @@ pub trait Event {
     /// Returns [`Version`] of this [`Event`].
     #[must_use]
     fn version(&self) -> Version;
+
+    /// Returns [`Meta`] of all the [`Versioned`] events this [`Event`] may
+    /// represent.
+    ///
+    /// For [`Event`] enums, lists [`Meta`] of every enum variant in their
+    /// declaration order, so may contain duplicates if some variants have the
+    /// same type.
+    ///
+    /// Collected via [`Event::visit_leaves()`], so shouldn't be overridden.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use arcana::es::event::{self, Event};
+    /// #
+    /// #[derive(event::Versioned)]
+    /// #[event(name = "chat.created", version = 1)]
+    /// struct ChatCreated;
+    ///
+    /// #[derive(event::Versioned)]
+    /// #[event(name = "message.posted", version = 2)]
+    /// struct MessagePosted;
+    ///
+    /// #[derive(Event)]
+    /// enum ChatEvent {
+    ///     Created(ChatCreated),
+    ///     MessagePosted(MessagePosted),
+    /// }
+    ///
+    /// let catalog = ChatEvent::meta()
+    ///     .into_iter()
+    ///     .map(|m| (m.name, m.version.get()))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(catalog, [("chat.created", 1), ("message.posted", 2)]);
+    /// ```
+    #[must_use]
+    fn meta() -> Vec<Meta>
+    where
+        Self: Sized,
+    {
+        /// [`Visitor`] collecting [`Meta`] of the visited leaves.
+        struct Collector(Vec<Meta>);
+
+        impl Visitor for Collector {
+            fn visit<Ev: Versioned>(&mut self) {
+                self.0.push(Meta {
+                    type_name: any::type_name::<Ev>(),
+                    name: Ev::NAME,
+                    version: Ev::VERSION,
+                    doc: Ev::DOC,
+                });
+            }
+        }
+
+        let mut collector = Collector(Vec::new());
+        Self::visit_leaves(&mut collector);
+        collector.0
+    }
+
+    /// Visits all the leaf [`Versioned`] event types this [`Event`] may
+    /// represent with the given [`Visitor`].
+    ///
+    /// For [`Event`] enums, recursively visits leaves of every enum variant in
+    /// their declaration order, so the same type may be visited several times.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use arcana::es::event::{self, Event};
+    /// #
+    /// #[derive(event::Versioned)]
+    /// #[event(name = "chat.created", version = 1)]
+    /// struct ChatCreated;
+    ///
+    /// #[derive(event::Versioned)]
+    /// #[event(name = "message.posted", version = 1)]
+    /// struct MessagePosted;
+    ///
+    /// #[derive(Event)]
+    /// enum ChatEvent {
+    ///     Created(ChatCreated),
+    /// }
+    ///
+    /// #[derive(Event)]
+    /// enum AnyEvent {
+    ///     Chat(ChatEvent),
+    ///     MessagePosted(MessagePosted),
+    /// }
+    ///
+    /// #[derive(Default)]
+    /// struct Names(Vec<event::Name>);
+    ///
+    /// impl event::Visitor for Names {
+    ///     fn visit<Ev: event::Versioned>(&mut self) {
+    ///         self.0.push(Ev::NAME);
+    ///     }
+    /// }
+    ///
+    /// let mut names = Names::default();
+    /// AnyEvent::visit_leaves(&mut names);
+    /// assert_eq!(names.0, ["chat.created", "message.posted"]);
+    /// ```
+    fn visit_leaves<V: Visitor>(visitor: &mut V)
+    where
+        Self: Sized;
 }
 
 impl<Ev: Versioned + ?Sized> Event for Ev {
@@ -81,6 +219,38 @@ impl<Ev: Versioned + ?Sized> Event for Ev {
     fn version(&self) -> Version {
         <Self as Versioned>::VERSION
     }
+
+    fn visit_leaves<V: Visitor>(visitor: &mut V)
+    where
+        Self: Sized,
+    {
+        visitor.visit::<Self>();
+    }
+}
+
+/// Visitor of leaf [`Versioned`] event types of an [`Event`].
+///
+/// See [`Event::visit_leaves()`] for details.
+pub trait Visitor {
+    /// Visits the given leaf [`Versioned`] event type.
+    fn visit<Ev: Versioned>(&mut self);
+}
+
+/// Description of a concrete [`Versioned`] [`Event`] type.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Meta {
+    /// Name of the Rust type of this [`Event`], as returned by
+    /// [`any::type_name()`].
+    pub type_name: &'static str,
+
+    /// [`Name`] of this [`Event`].
+    pub name: Name,
+
+    /// [`Version`] of this [`Event`].
+    pub version: Version,
+
+    /// [`Doc`]umentation of this [`Event`].
+    pub doc: Doc,
 }
 
 /// State that can be calculated by applying the specified [`Event`].
@@ -184,6 +354,149 @@ impl<'e, S: ?Sized> Sourced<dyn Sourcing<S> + Send + Sync + 'e> for S {
     }
 }
 
+/// Applies all the given [`Event`]s to the specified `state` in order.
+///
+/// Accepts any [`IntoIterator`] over pointers to [`Event`]s (anything that
+/// [`Deref`]s to them), like `&Ev`, `Arc<Ev>` or `Box<dyn Sourcing<S>>` with
+/// any combination of [`Send`]/[`Sync`] bounds, choosing the appropriate
+/// [`Sourced`] impl automatically.
+///
+/// # Example
+///
+/// ```rust
+/// # use arcana::es::event;
+/// #
+/// #[derive(Debug, Eq, PartialEq)]
+/// struct Chat {
+///     message_count: usize,
+/// }
+///
+/// #[derive(event::Versioned)]
+/// #[event(name = "chat.created", version = 1)]
+/// struct ChatCreated;
+///
+/// #[derive(event::Versioned)]
+/// #[event(name = "message.posted", version = 1)]
+/// struct MessagePosted;
+///
+/// impl event::Initialized<ChatCreated> for Chat {
+///     fn init(_: &ChatCreated) -> Self {
+///         Self { message_count: 0 }
+///     }
+/// }
+///
+/// impl event::Sourced<MessagePosted> for Chat {
+///     fn apply(&mut self, _: &MessagePosted) {
+///         self.message_count += 1;
+///     }
+/// }
+///
+/// let events: Vec<Box<dyn event::Sourcing<Option<Chat>> + Send>> = vec![
+///     Box::new(event::Initial(ChatCreated)),
+///     Box::new(MessagePosted),
+///     Box::new(MessagePosted),
+/// ];
+///
+/// let mut chat = None;
+/// event::apply_all(&mut chat, events);
+/// assert_eq!(chat, Some(Chat { message_count: 2 }));
+///
+/// event::apply_all(&mut chat, &[MessagePosted]);
+/// assert_eq!(chat, Some(Chat { message_count: 3 }));
+/// ```
+pub fn apply_all<S, Ev, I>(state: &mut S, events: I)
+where
+    S: Sourced<Ev> + ?Sized,
+    Ev: ?Sized,
+    I: IntoIterator,
+    I::Item: Deref<Target = Ev>,
+{
+    for ev in events {
+        state.apply(&*ev);
+    }
+}
+
+/// Implements [`Sourced`] for the given states and [`Event`]s with the provided
+/// closure-like bodies.
+///
+/// Useful for [`Event`]s defined in other crates, which cannot be decorated
+/// with derives or attributes.
+///
+/// Each entry has the `State applies Event => |state, event| body` form.
+/// Entries are separated with `;`, which may be omitted after a block `body`.
+///
+/// _Note:_ Orphan rules still apply, so either the state or the [`Event`]
+/// should be defined in the current crate.
+///
+/// # Example
+///
+/// ```rust
+/// # use arcana::es::event;
+/// #
+/// mod ext {
+///     # use arcana::es::event;
+///     #
+///     #[derive(event::Versioned)]
+///     #[event(name = "message.posted", version = 1)]
+///     pub struct MessagePosted;
+///
+///     #[derive(event::Versioned)]
+///     #[event(name = "message.deleted", version = 1)]
+///     pub struct MessageDeleted;
+/// }
+///
+/// #[derive(Debug, Default, Eq, PartialEq)]
+/// struct Chat {
+///     message_count: usize,
+/// }
+///
+/// arcana::sourced! {
+///     Chat applies ext::MessagePosted => |chat, _| chat.message_count += 1;
+///     Chat applies ext::MessageDeleted => |chat, _| {
+///         chat.message_count -= 1;
+///     }
+/// }
+///
+/// let mut chat = Chat::default();
+/// event::apply_all(&mut chat, &[ext::MessagePosted, ext::MessagePosted]);
+/// event::apply_all(&mut chat, &[ext::MessageDeleted]);
+/// assert_eq!(chat, Chat { message_count: 1 });
+/// ```
+#[macro_export]
+macro_rules! sourced {
+    (@impl [$($state:tt)+] $ev:ty, $s:pat_param, $e:pat_param, $body:expr) => {
+        impl $crate::es::event::Sourced<$ev> for $($state)+ {
+            fn apply(&mut self, event: &$ev) {
+                let $s = self;
+                let $e = event;
+                $body;
+            }
+        }
+    };
+    (@state [$($state:tt)+] applies $ev:ty =>
+        |$s:pat_param, $e:pat_param| $body:block $($rest:tt)*
+    ) => {
+        $crate::sourced!(@impl [$($state)+] $ev, $s, $e, $body);
+        $crate::sourced!(@state [] $($rest)*);
+    };
+    (@state [$($state:tt)+] applies $ev:ty =>
+        |$s:pat_param, $e:pat_param| $body:expr $(; $($rest:tt)*)?
+    ) => {
+        $crate::sourced!(@impl [$($state)+] $ev, $s, $e, $body);
+        $( $crate::sourced!(@state [] $($rest)*); )?
+    };
+    (@state [] ; $($rest:tt)*) => {
+        $crate::sourced!(@state [] $($rest)*);
+    };
+    (@state [$($state:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::sourced!(@state [$($state)* $next] $($rest)*);
+    };
+    (@state []) => {};
+    ($($input:tt)+) => {
+        $crate::sourced!(@state [] $($input)+);
+    };
+}
+
 /// Before a state can be [`Sourced`] it needs to be [`Initialized`].
 pub trait Initialized<Ev: ?Sized> {
     /// Creates an initial state from the given [`Event`].
@@ -215,12 +528,264 @@ impl<Ev: Event + ?Sized, S: Initialized<Ev>> Sourced<Initial<Ev>>
     }
 }
 
+/// Wrapper type reusing the same [`Versioned`] [`Event`] payload as another
+/// revision `V` of it.
+///
+/// Keeps the [`Versioned::NAME`] of the wrapped [`Event`], while overriding its
+/// [`Versioned::VERSION`] with `V`, which must neither be `0` (zero), nor the
+/// [`Versioned::VERSION`] of the wrapped [`Event`] itself.
+///
+/// # Example
+///
+/// ```rust
+/// # use arcana::es::event::{self, Event as _, Revision};
+/// #
+/// #[derive(event::Versioned)]
+/// #[event(name = "message.posted", version = 1)]
+/// struct MessagePosted {
+///     text: String,
+/// }
+///
+/// #[derive(Debug, Default, Eq, PartialEq)]
+/// struct Chat {
+///     messages: Vec<String>,
+/// }
+///
+/// impl event::Sourced<MessagePosted> for Chat {
+///     fn apply(&mut self, ev: &MessagePosted) {
+///         self.messages.push(ev.text.clone());
+///     }
+/// }
+///
+/// impl event::Sourced<Revision<MessagePosted, 2>> for Chat {
+///     fn apply(&mut self, ev: &Revision<MessagePosted, 2>) {
+///         self.messages.push(ev.text.trim().to_owned());
+///     }
+/// }
+///
+/// #[derive(event::Event)]
+/// enum ChatEvent {
+///     MessagePosted(MessagePosted),
+///     MessagePostedV2(Revision<MessagePosted, 2>),
+/// }
+///
+/// let ev = ChatEvent::MessagePostedV2(Revision(MessagePosted {
+///     text: " hello ".to_owned(),
+/// }));
+/// assert_eq!(ev.name(), "message.posted");
+/// assert_eq!(ev.version().get(), 2);
+///
+/// let mut chat = Some(Chat::default());
+/// event::Sourced::apply(&mut chat, &ev);
+/// assert_eq!(chat.unwrap().messages, ["hello"]);
+/// ```
+///
+/// Different Rust types still cannot have the same [`Name`] and [`Version`]
+/// inside a single [`Event`] enum:
+///
+/// ```rust,compile_fail,E0080
+/// # use arcana::es::event::{self, Revision};
+/// #
+/// #[derive(event::Versioned)]
+/// #[event(name = "chat", version = 1)]
+/// struct ChatEvent;
+///
+/// #[derive(event::Versioned)]
+/// #[event(name = "chat", version = 3)]
+/// struct DuplicateChatEvent;
+///
+/// #[derive(event::Event)]
+/// enum AnyEvent {
+///     Chat(Revision<ChatEvent, 2>),
+///     DuplicateChat(Revision<DuplicateChatEvent, 2>),
+/// }
+/// ```
+///
+/// Neither can a [`Revision`] repeat the [`Version`] of the wrapped [`Event`]:
+///
+/// ```rust,compile_fail,E0080
+/// # use arcana::es::event::{self, Revision};
+/// #
+/// #[derive(event::Versioned)]
+/// #[event(name = "message.posted", version = 1)]
+/// struct MessagePosted;
+///
+/// #[derive(event::Event)]
+/// enum ChatEvent {
+///     MessagePosted(MessagePosted),
+///     MessagePostedV1(Revision<MessagePosted, 1>),
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Deref, DerefMut, Display, RefCast)]
+#[repr(transparent)]
+pub struct Revision<Ev: ?Sized, const V: u16>(pub Ev);
+
+// Manual implementation due to `derive_more::From` not being able to strip
+// `?Sized` trait bound.
+impl<Ev, const V: u16> From<Ev> for Revision<Ev, V> {
+    fn from(ev: Ev) -> Self {
+        Self(ev)
+    }
+}
+
+impl<Ev: Versioned + ?Sized, const V: u16> Versioned for Revision<Ev, V> {
+    const NAME: Name = Ev::NAME;
+
+    const VERSION: Version = match NonZeroU16::new(V) {
+        Some(ver) if ver.get() == Ev::VERSION.get() => panic!(
+            "`Revision` of an `Event` should differ from its own `Version`",
+        ),
+        Some(ver) => Version(ver),
+        None => panic!("`Revision` of an `Event` should not be 0 (zero)"),
+    };
+
+    const DOC: Doc = Ev::DOC;
+}
+
+/// Error of converting an [`Event`] enum into the type of one of its variants,
+/// while it holds another variant.
+///
+/// Contains the original [`Event`], so it may be reused.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct WrongVariant<Ev>(pub Ev);
+
+impl<Ev> fmt::Display for WrongVariant<Ev> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}` holds another variant", any::type_name::<Ev>())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Ev: fmt::Debug> error::Error for WrongVariant<Ev> {}
+
+/// [`Event`] related to a particular entity, identified by its [`Keyed::Key`].
+///
+/// Allows a single [`Event`] stream to source many entities at once (e.g. all
+/// the messages of a chat), by applying each [`Event`] to the entity with the
+/// matching [`Keyed::Key`] inside a `HashMap`.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::collections::HashMap;
+/// #
+/// # use arcana::es::event::{self, Sourced as _};
+/// #
+/// #[derive(Debug, Eq, PartialEq)]
+/// struct Message {
+///     edits: usize,
+/// }
+///
+/// #[derive(event::Versioned)]
+/// #[event(name = "message.posted", version = 1)]
+/// struct MessagePosted {
+///     id: u32,
+/// }
+///
+/// #[derive(event::Versioned)]
+/// #[event(name = "message.edited", version = 1)]
+/// struct MessageEdited {
+///     id: u32,
+/// }
+///
+/// impl event::Keyed for MessagePosted {
+///     type Key = u32;
+///
+///     fn key(&self) -> &u32 {
+///         &self.id
+///     }
+/// }
+///
+/// impl event::Keyed for MessageEdited {
+///     type Key = u32;
+///
+///     fn key(&self) -> &u32 {
+///         &self.id
+///     }
+/// }
+///
+/// impl event::Initialized<MessagePosted> for Message {
+///     fn init(_: &MessagePosted) -> Self {
+///         Self { edits: 0 }
+///     }
+/// }
+///
+/// impl event::Sourced<MessageEdited> for Message {
+///     fn apply(&mut self, _: &MessageEdited) {
+///         self.edits += 1;
+///     }
+/// }
+///
+/// impl event::Sourced<event::Revision<MessageEdited, 2>> for Message {
+///     fn apply(&mut self, _: &event::Revision<MessageEdited, 2>) {
+///         self.edits += 1;
+///     }
+/// }
+///
+/// let mut messages = HashMap::<u32, Message>::new();
+/// messages.apply(&event::Initial(MessagePosted { id: 1 }));
+/// messages.apply(&event::Initial(MessagePosted { id: 2 }));
+/// messages.apply(&MessageEdited { id: 2 });
+/// messages.apply(&MessageEdited { id: 3 }); // no such message, ignored
+/// messages.apply(&event::Revision::<_, 2>(MessageEdited { id: 1 }));
+///
+/// assert_eq!(messages.len(), 2);
+/// assert_eq!(messages[&1], Message { edits: 1 });
+/// assert_eq!(messages[&2], Message { edits: 1 });
+/// ```
+pub trait Keyed {
+    /// Type of the key identifying the entity this [`Event`] relates to.
+    type Key;
+
+    /// Returns the [`Keyed::Key`] of the entity this [`Event`] relates to.
+    #[must_use]
+    fn key(&self) -> &Self::Key;
+}
+
+impl<Ev: Keyed + ?Sized> Keyed for Initial<Ev> {
+    type Key = Ev::Key;
+
+    fn key(&self) -> &Self::Key {
+        self.0.key()
+    }
+}
+
+impl<Ev: Keyed + ?Sized, const V: u16> Keyed for Revision<Ev, V> {
+    type Key = Ev::Key;
+
+    fn key(&self) -> &Self::Key {
+        self.0.key()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Ev, S, H> Sourced<Ev> for HashMap<Ev::Key, S, H>
+where
+    Ev: Keyed + ?Sized,
+    Ev::Key: Clone + Eq + Hash,
+    H: BuildHasher,
+    Option<S>: Sourced<Ev>,
+{
+    fn apply(&mut self, event: &Ev) {
+        let (key, mut state) = match self.remove_entry(event.key()) {
+            Some((key, state)) => (key, Some(state)),
+            None => (event.key().clone(), None),
+        };
+        state.apply(event);
+        if let Some(state) = state {
+            drop(self.insert(key, state));
+        }
+    }
+}
+
 #[cfg(feature = "codegen")]
 pub mod codegen {
     //! [`Event`] machinery aiding codegen.
     //!
     //! [`Event`]: super::Event
 
+    use core::cmp::Ordering;
+
     /// Tracking of [`VersionedEvent`]s number.
     ///
     /// [`VersionedEvent`]: super::Versioned
@@ -232,6 +797,40 @@ pub mod codegen {
         const COUNT: usize;
     }
 
+    /// Unique identifier of a [`VersionedEvent`] Rust type.
+    ///
+    /// [`VersionedEvent`]: super::Versioned
+    pub trait Unique {
+        /// Unique identifier of this [`VersionedEvent`] Rust type.
+        ///
+        /// [`VersionedEvent`]: super::Versioned
+        const ID: &'static str;
+    }
+
+    impl<Ev, const V: u16> Versioned for super::Revision<Ev, V>
+    where
+        Ev: Unique + ?Sized,
+    {
+        const COUNT: usize = 1;
+    }
+
+    impl<Ev, const V: u16> super::Revision<Ev, V>
+    where
+        Ev: super::Versioned + Unique + ?Sized,
+    {
+        #[doc(hidden)]
+        #[inline]
+        #[must_use]
+        pub const fn __arcana_events() -> [(&'static str, &'static str, u16); 1]
+        {
+            [(
+                <Ev as Unique>::ID,
+                <Self as super::Versioned>::NAME,
+                <Self as super::Versioned>::VERSION.get(),
+            )]
+        }
+    }
+
     /// Checks in compile time whether all the given combinations of
     /// [`Event::name`] and [`Event::version`] correspond to different Rust
     /// types.
@@ -248,6 +847,11 @@ pub mod codegen {
     /// Correctness is checked then with asserting this function at compile time
     /// in `const` context.
     ///
+    /// To keep `const` evaluation cheap for large [`Event`]s, the array is
+    /// sorted by [`event::Name`] and [`event::Version`] first (with an in-place
+    /// heapsort), so only adjacent entries have to be compared afterwards,
+    /// making the whole check `O(n log n)` rather than `O(n²)`.
+    ///
     /// [`Event`]: super::Event
     /// [`Event::name`]: super::Event::name
     /// [`Event::version`]: super::Event::version
@@ -256,28 +860,101 @@ pub mod codegen {
     /// [`event::Versioned`]: super::Versioned
     #[must_use]
     pub const fn has_different_types_with_same_name_and_ver<const N: usize>(
-        events: [(&str, &str, u16); N],
+        mut events: [(&str, &str, u16); N],
     ) -> bool {
-        let mut outer = 0;
-        while outer < events.len() {
-            let mut inner = outer + 1;
-            while inner < events.len() {
-                let (inner_ty, inner_name, inner_ver) = events[inner];
-                let (outer_ty, outer_name, outer_ver) = events[outer];
-                if !str_eq(inner_ty, outer_ty)
-                    && str_eq(inner_name, outer_name)
-                    && inner_ver == outer_ver
+        // Heapsort is used, as it requires neither recursion nor `&mut`
+        // references to be passed into other `const fn`s, both being unusable
+        // in `const` context on our MSRV. Both heap building and sorting
+        // phases are merged into a single loop for the same reason.
+        let (mut start, mut end) = (N / 2, N);
+        while end > 1 {
+            if start > 0 {
+                start -= 1;
+            } else {
+                end -= 1;
+                let tmp = events[end];
+                events[end] = events[0];
+                events[0] = tmp;
+            }
+
+            let mut root = start;
+            loop {
+                let mut child = 2 * root + 1;
+                if child >= end {
+                    break;
+                }
+                if child + 1 < end && is_less(events[child], events[child + 1])
                 {
-                    return true;
+                    child += 1;
+                }
+                if !is_less(events[root], events[child]) {
+                    break;
                 }
-                inner += 1;
+                let tmp = events[root];
+                events[root] = events[child];
+                events[child] = tmp;
+                root = child;
             }
-            outer += 1;
+        }
+
+        let mut i = 1;
+        while i < N {
+            let (prev_ty, prev_name, prev_ver) = events[i - 1];
+            let (ty, name, ver) = events[i];
+            if ver == prev_ver
+                && str_eq(name, prev_name)
+                && !str_eq(ty, prev_ty)
+            {
+                return true;
+            }
+            i += 1;
         }
 
         false
     }
 
+    /// Checks whether the `l`eft event entry precedes the `r`ight one, when
+    /// ordered by [`event::Name`] and then by [`event::Version`].
+    ///
+    /// [`event::Name`]: super::Name
+    /// [`event::Version`]: super::Version
+    const fn is_less(l: (&str, &str, u16), r: (&str, &str, u16)) -> bool {
+        match str_cmp(l.1, r.1) {
+            Ordering::Less => true,
+            Ordering::Greater => false,
+            Ordering::Equal => l.2 < r.2,
+        }
+    }
+
+    /// Lexicographically compares strings in `const` context.
+    ///
+    /// As there is no `const impl Trait` and `l.cmp(r)` calls [`Ord`], we have
+    /// to write custom comparison function.
+    ///
+    /// [`Ord`]: std::cmp::Ord
+    // TODO: Remove once `Ord` trait is allowed in `const` context.
+    const fn str_cmp(l: &str, r: &str) -> Ordering {
+        let (l, r) = (l.as_bytes(), r.as_bytes());
+        let mut i = 0;
+        while i < l.len() && i < r.len() {
+            if l[i] < r[i] {
+                return Ordering::Less;
+            }
+            if l[i] > r[i] {
+                return Ordering::Greater;
+            }
+            i += 1;
+        }
+
+        if l.len() < r.len() {
+            Ordering::Less
+        } else if l.len() > r.len() {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+
     /// Compares strings in `const` context.
     ///
     /// As there is no `const impl Trait` and `l == r` calls [`Eq`], we have to
@@ -301,4 +978,65 @@ pub mod codegen {
 
         true
     }
+
+    #[cfg(test)]
+    mod spec {
+        use super::has_different_types_with_same_name_and_ver as check;
+
+        #[test]
+        fn allows_same_type_with_same_name_and_ver() {
+            assert!(!check([
+                ("a", "chat", 1),
+                ("b", "chat", 2),
+                ("a", "chat", 1),
+                ("c", "message", 1),
+            ]));
+        }
+
+        #[test]
+        fn detects_different_types_with_same_name_and_ver() {
+            assert!(check([
+                ("a", "chat", 1),
+                ("c", "message", 1),
+                ("b", "chat", 2),
+                ("d", "chat", 1),
+            ]));
+            assert!(check([
+                ("a", "chat", 1),
+                ("a", "chat", 1),
+                ("b", "chat", 1),
+                ("a", "chat", 1),
+            ]));
+        }
+
+        #[test]
+        fn handles_trivial_inputs() {
+            assert!(!check([]));
+            assert!(!check([("a", "chat", 1)]));
+        }
+
+        #[test]
+        fn evaluates_large_inputs_in_const_context() {
+            const N: usize = 256;
+
+            const fn events(
+                dup: bool,
+            ) -> [(&'static str, &'static str, u16); N] {
+                let mut events = [("", "", 0); N];
+                let (mut i, mut ver) = (0, 1);
+                while i < N {
+                    events[N - 1 - i] = ("a", "chat", ver);
+                    i += 1;
+                    ver += 1;
+                }
+                if dup {
+                    events[N / 3] = ("b", "chat", 1);
+                }
+                events
+            }
+
+            const _: () = assert!(!check(events(false)));
+            const _: () = assert!(check(events(true)));
+        }
+    }
 }
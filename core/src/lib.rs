@@ -1,5 +1,6 @@
 #![doc = include_str!("../README.md")]
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
+#![cfg_attr(not(feature = "std"), no_std)]
 #![deny(
     macro_use_extern_crate,
     nonstandard_style,
@@ -62,7 +63,6 @@
     clippy::string_add,
     clippy::string_lit_as_bytes,
     clippy::string_slice,
-    clippy::string_to_string,
     clippy::suboptimal_flops,
     clippy::suspicious_operation_groupings,
     clippy::todo,
@@ -100,6 +100,9 @@
     variant_size_differences
 )]
 
+#[cfg(feature = "es")]
+extern crate alloc;
+
 #[cfg(feature = "es")]
 pub mod es;
 
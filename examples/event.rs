@@ -9,21 +9,26 @@ struct ChatCreated;
 struct MessagePosted;
 
 #[derive(Event)]
+#[event(state = Chat)]
 enum ChatEvent {
-    #[event(init)]
+    #[event(init, from(ChatCreated))]
     Created(ChatCreated),
+    #[event(from(MessagePosted))]
     MessagePosted(MessagePosted),
 }
 
 #[derive(Event)]
+#[event(state = Message)]
 enum MessageEvent {
-    #[event(init)]
+    #[event(init, from(MessagePosted))]
     MessagePosted(MessagePosted),
 }
 
 #[derive(Event)]
 enum AnyEvent {
+    #[event(from(ChatEvent, ChatCreated))]
     Chat(ChatEvent),
+    #[event(from(MessageEvent, MessagePosted))]
     Message(MessageEvent),
 }
 
@@ -57,7 +62,7 @@ fn main() {
     let mut chat = Option::<Chat>::None;
     let mut message = Option::<Message>::None;
 
-    let ev = ChatEvent::Created(ChatCreated.into());
+    let ev = ChatEvent::Created(ChatCreated);
     chat.apply(&ev);
     assert_eq!(ev.name(), "chat.created");
     assert_eq!(chat, Some(Chat { message_count: 0 }));
@@ -71,15 +76,14 @@ fn main() {
     chat.apply(ev);
     assert_eq!(chat, Some(Chat { message_count: 2 }));
 
-    let ev = MessageEvent::MessagePosted(MessagePosted.into());
+    let ev = MessageEvent::MessagePosted(MessagePosted);
     message.apply(&ev);
     assert_eq!(ev.name(), "message.posted");
     assert_eq!(message, Some(Message));
 
-    let ev = AnyEvent::Chat(ChatEvent::Created(ChatCreated.into()));
+    let ev = AnyEvent::from(ChatCreated);
     assert_eq!(ev.name(), "chat.created");
 
-    let ev =
-        AnyEvent::Message(MessageEvent::MessagePosted(MessagePosted.into()));
+    let ev = AnyEvent::from(MessagePosted);
     assert_eq!(ev.name(), "message.posted");
 }
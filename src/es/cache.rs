@@ -0,0 +1,6 @@
+//! Caching of [`Sourced`] states.
+//!
+//! [`Sourced`]: super::EventSourced
+
+#[doc(inline)]
+pub use arcana_core::es::cache::Materialized;
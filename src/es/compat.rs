@@ -0,0 +1,6 @@
+//! Compatibility checks between [`Event`] catalogs.
+//!
+//! [`Event`]: super::Event
+
+#[doc(inline)]
+pub use arcana_core::es::compat::{diff, Incompatibility};
@@ -2,7 +2,8 @@
 
 #[doc(inline)]
 pub use arcana_core::es::event::{
-    Event, Initial, Initialized, Name, Sourced, Sourcing, Version, Versioned,
+    apply_all, Doc, Event, Initial, Initialized, Keyed, Meta, Name, Revision,
+    Sourced, Sourcing, Version, Versioned, Visitor, WrongVariant,
 };
 
 #[cfg(feature = "derive")]
@@ -2,11 +2,14 @@
 //!
 //! [Event Sourcing]: https://martinfowler.com/eaaDev/EventSourcing.html
 
+pub mod cache;
+pub mod compat;
 pub mod event;
 
 #[doc(inline)]
 pub use self::event::{
-    Event, Initialized as EventInitialized, Name as EventName,
+    Doc as EventDoc, Event, Initialized as EventInitialized,
+    Keyed as KeyedEvent, Meta as EventMeta, Name as EventName,
     Sourced as EventSourced, Sourcing as EventSourcing,
     Version as EventVersion, Versioned as VersionedEvent,
 };
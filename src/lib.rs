@@ -1,5 +1,6 @@
 #![doc = include_str!("../README.md")]
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
+#![cfg_attr(not(feature = "std"), no_std)]
 #![deny(
     macro_use_extern_crate,
     nonstandard_style,
@@ -62,7 +63,6 @@
     clippy::string_add,
     clippy::string_lit_as_bytes,
     clippy::string_slice,
-    clippy::string_to_string,
     clippy::suboptimal_flops,
     clippy::suspicious_operation_groupings,
     clippy::todo,
@@ -107,6 +107,10 @@ use arcana_codegen as _;
 
 pub use arcana_core::RefCast;
 
+#[cfg(feature = "es")]
+#[doc(inline)]
+pub use arcana_core::sourced;
+
 #[cfg(feature = "derive")]
 /// See [`sealed`] crate documentation.
 ///